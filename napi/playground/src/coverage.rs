@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+
+use oxc::{
+    allocator::{Allocator, Vec as ArenaVec},
+    ast::{
+        AstBuilder,
+        ast::{
+            ArrowFunctionExpression, Expression, Function, IfStatement, Program, Statement,
+            SwitchStatement,
+        },
+    },
+    ast_visit::{VisitMut, utf8_to_utf16::Utf16SpanConverter, walk_mut},
+    parser::Parser,
+    semantic::ScopeFlags,
+    span::{GetSpan, SourceType, Span},
+};
+use serde::Serialize;
+
+#[derive(Default, Serialize)]
+pub struct CoverageMap {
+    #[serde(rename = "statementMap")]
+    statement_map: BTreeMap<u32, Location>,
+    #[serde(rename = "fnMap")]
+    fn_map: BTreeMap<u32, FunctionLocation>,
+    #[serde(rename = "branchMap")]
+    branch_map: BTreeMap<u32, BranchLocation>,
+    s: BTreeMap<u32, u32>,
+    f: BTreeMap<u32, u32>,
+    b: BTreeMap<u32, Vec<u32>>,
+}
+
+#[derive(Clone, Serialize)]
+struct Location {
+    start: u32,
+    end: u32,
+}
+
+impl From<Span> for Location {
+    fn from(span: Span) -> Self {
+        Self { start: span.start, end: span.end }
+    }
+}
+
+#[derive(Serialize)]
+struct FunctionLocation {
+    name: String,
+    decl: Location,
+    loc: Location,
+}
+
+#[derive(Serialize)]
+struct BranchLocation {
+    r#type: &'static str,
+    locations: Vec<Location>,
+}
+
+/// Rewrites an AST to count statement, function and branch executions, Istanbul-style.
+///
+/// Runs before minification so the recorded spans still match the original source text.
+pub struct CoverageInstrumenter<'a> {
+    ast: AstBuilder<'a>,
+    converter: Option<Utf16SpanConverter<'a>>,
+    map: CoverageMap,
+    next_statement_id: u32,
+    next_function_id: u32,
+    next_branch_id: u32,
+}
+
+impl<'a> CoverageInstrumenter<'a> {
+    pub fn new(allocator: &'a Allocator, converter: Option<Utf16SpanConverter<'a>>) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            converter,
+            map: CoverageMap::default(),
+            next_statement_id: 0,
+            next_function_id: 0,
+            next_branch_id: 0,
+        }
+    }
+
+    /// Instruments `program` in place and returns the serialized coverage map.
+    pub fn build(mut self, program: &mut Program<'a>) -> String {
+        self.visit_program(program);
+        let preamble = self.parse_snippet(&self.preamble_source());
+        program.body.splice(0..0, preamble);
+        serde_json::to_string(&self.map).unwrap_or_default()
+    }
+
+    fn location(&mut self, span: Span) -> Location {
+        let mut span = span;
+        if let Some(converter) = &mut self.converter {
+            converter.convert_span(&mut span);
+        }
+        span.into()
+    }
+
+    fn parse_snippet(&self, source_text: &str) -> ArenaVec<'a, Statement<'a>> {
+        let source_text = self.ast.allocator.alloc_str(source_text);
+        Parser::new(self.ast.allocator, source_text, SourceType::default()).parse().program.body
+    }
+
+    fn counter_statement(&self, bucket: char, id: u32, arm: Option<u32>) -> Statement<'a> {
+        let source_text = match arm {
+            Some(arm) => format!("__coverage__.{bucket}[{id}][{arm}]++;"),
+            None => format!("__coverage__.{bucket}[{id}]++;"),
+        };
+        self.parse_snippet(&source_text).remove(0)
+    }
+
+    fn counter_expression(&self, bucket: char, id: u32, arm: Option<u32>) -> Expression<'a> {
+        let Statement::ExpressionStatement(stmt) = self.counter_statement(bucket, id, arm) else {
+            unreachable!("counter_statement always produces an expression statement")
+        };
+        stmt.unbox().expression
+    }
+
+    fn record_function(&mut self, name: String, decl: Span, body: Span) -> u32 {
+        let id = self.next_function_id;
+        self.next_function_id += 1;
+        let decl = self.location(decl);
+        let loc = self.location(body);
+        self.map.fn_map.insert(id, FunctionLocation { name, decl, loc });
+        self.map.f.insert(id, 0);
+        id
+    }
+
+    fn preamble_source(&self) -> String {
+        format!(
+            "var __coverage__ = {{\"statementMap\":{},\"fnMap\":{},\"branchMap\":{},\"s\":{},\"f\":{},\"b\":{}}};",
+            serde_json::to_string(&self.map.statement_map).unwrap_or_default(),
+            serde_json::to_string(&self.map.fn_map).unwrap_or_default(),
+            serde_json::to_string(&self.map.branch_map).unwrap_or_default(),
+            serde_json::to_string(&self.map.s).unwrap_or_default(),
+            serde_json::to_string(&self.map.f).unwrap_or_default(),
+            serde_json::to_string(&self.map.b).unwrap_or_default(),
+        )
+    }
+
+    /// Wraps `target` in a block (if it isn't one already) and prepends `counter`.
+    fn prepend_into_block(&self, target: &mut Statement<'a>, counter: Statement<'a>) {
+        if let Statement::BlockStatement(block) = target {
+            block.body.insert(0, counter);
+            return;
+        }
+        let span = target.span();
+        let placeholder = self.ast.statement_empty(span);
+        let original = std::mem::replace(target, placeholder);
+        let mut body = self.ast.vec_with_capacity(2);
+        body.push(counter);
+        body.push(original);
+        *target = Statement::BlockStatement(self.ast.alloc_block_statement(span, body));
+    }
+
+    /// Replaces `expr` with `(__coverage__.<bucket>[id][arm]++, expr)` (or without the
+    /// `[arm]` subscript when `arm` is `None`), preserving `expr` as the evaluated value.
+    fn wrap_with_counter(&mut self, expr: &mut Expression<'a>, bucket: char, id: u32, arm: Option<u32>) {
+        let span = expr.span();
+        let placeholder = self.ast.expression_null_literal(span);
+        let original = std::mem::replace(expr, placeholder);
+        let counter = self.counter_expression(bucket, id, arm);
+        let mut expressions = self.ast.vec_with_capacity(2);
+        expressions.push(counter);
+        expressions.push(original);
+        *expr = Expression::SequenceExpression(self.ast.alloc_sequence_expression(span, expressions));
+    }
+}
+
+impl<'a> VisitMut<'a> for CoverageInstrumenter<'a> {
+    fn visit_statements(&mut self, statements: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut instrumented = self.ast.vec_with_capacity(statements.len() * 2);
+        for mut statement in statements.drain(..) {
+            self.visit_statement(&mut statement);
+            let id = self.next_statement_id;
+            self.next_statement_id += 1;
+            let location = self.location(statement.span());
+            self.map.statement_map.insert(id, location);
+            self.map.s.insert(id, 0);
+            instrumented.push(self.counter_statement('s', id, None));
+            instrumented.push(statement);
+        }
+        *statements = instrumented;
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        let name = func.id.as_ref().map_or_else(|| "<anonymous>".to_string(), |id| id.name.to_string());
+        let span = func.span;
+
+        walk_mut::walk_function(self, func, flags);
+
+        if let Some(body) = &mut func.body {
+            let id = self.record_function(name, span, body.span);
+            let counter = self.counter_statement('f', id, None);
+            body.statements.insert(0, counter);
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        let span = arrow.span;
+        walk_mut::walk_formal_parameters(self, &mut arrow.params);
+
+        if arrow.expression {
+            // A concise arrow body (`() => expr`) is a single implicit-return expression
+            // statement. Recursing into it via `walk_function_body`/`visit_statements` would
+            // prepend an `s` counter ahead of it, turning the body into two statements and
+            // breaking the single-expression invariant codegen relies on for
+            // `arrow.expression`. Visit the expression directly and wrap it in place instead.
+            if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first_mut() {
+                self.visit_expression(&mut stmt.expression);
+                let id = self.record_function("<anonymous>".to_string(), span, arrow.body.span);
+                self.wrap_with_counter(&mut stmt.expression, 'f', id, None);
+            }
+        } else {
+            walk_mut::walk_function_body(self, &mut arrow.body);
+            let id = self.record_function("<anonymous>".to_string(), span, arrow.body.span);
+            let counter = self.counter_statement('f', id, None);
+            arrow.body.statements.insert(0, counter);
+        }
+    }
+
+    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
+        let id = self.next_branch_id;
+        self.next_branch_id += 1;
+        let consequent_span = self.location(stmt.consequent.span());
+        let alternate_span = stmt.alternate.as_ref().map(|alt| self.location(alt.span()));
+
+        walk_mut::walk_if_statement(self, stmt);
+
+        self.map.branch_map.insert(
+            id,
+            BranchLocation {
+                r#type: "if",
+                locations: vec![
+                    consequent_span.clone(),
+                    alternate_span.unwrap_or(consequent_span),
+                ],
+            },
+        );
+        self.map.b.insert(id, vec![0, 0]);
+
+        let consequent_counter = self.counter_statement('b', id, Some(0));
+        self.prepend_into_block(&mut stmt.consequent, consequent_counter);
+        if let Some(alternate) = &mut stmt.alternate {
+            let alternate_counter = self.counter_statement('b', id, Some(1));
+            self.prepend_into_block(alternate, alternate_counter);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::LogicalExpression(logical) => {
+                self.visit_expression(&mut logical.left);
+                self.visit_expression(&mut logical.right);
+                let id = self.next_branch_id;
+                self.next_branch_id += 1;
+                let left_loc = self.location(logical.left.span());
+                let right_loc = self.location(logical.right.span());
+                self.map.branch_map.insert(
+                    id,
+                    BranchLocation { r#type: "logical", locations: vec![left_loc, right_loc] },
+                );
+                self.map.b.insert(id, vec![0, 0]);
+                self.wrap_with_counter(&mut logical.left, 'b', id, Some(0));
+                self.wrap_with_counter(&mut logical.right, 'b', id, Some(1));
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.visit_expression(&mut conditional.test);
+                self.visit_expression(&mut conditional.consequent);
+                self.visit_expression(&mut conditional.alternate);
+                let id = self.next_branch_id;
+                self.next_branch_id += 1;
+                let consequent_loc = self.location(conditional.consequent.span());
+                let alternate_loc = self.location(conditional.alternate.span());
+                self.map.branch_map.insert(
+                    id,
+                    BranchLocation {
+                        r#type: "cond-expr",
+                        locations: vec![consequent_loc, alternate_loc],
+                    },
+                );
+                self.map.b.insert(id, vec![0, 0]);
+                self.wrap_with_counter(&mut conditional.consequent, 'b', id, Some(0));
+                self.wrap_with_counter(&mut conditional.alternate, 'b', id, Some(1));
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        let id = self.next_branch_id;
+        self.next_branch_id += 1;
+        let locations = stmt.cases.iter().map(|case| self.location(case.span)).collect::<Vec<_>>();
+
+        walk_mut::walk_switch_statement(self, stmt);
+
+        self.map.branch_map.insert(id, BranchLocation { r#type: "switch", locations });
+        self.map.b.insert(id, vec![0; stmt.cases.len()]);
+
+        for (index, case) in stmt.cases.iter_mut().enumerate() {
+            #[expect(clippy::cast_possible_truncation)]
+            let arm = index as u32;
+            let counter = self.counter_statement('b', id, Some(arm));
+            case.consequent.insert(0, counter);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     cell::{Cell, RefCell},
+    collections::HashSet,
     path::{Path, PathBuf},
     rc::Rc,
     sync::Arc,
@@ -8,28 +9,34 @@ use std::{
 use napi_derive::napi;
 use oxc::{
     allocator::Allocator,
-    ast::{Comment as OxcComment, CommentKind, ast::Program},
+    ast::{AstKind, Comment as OxcComment, CommentKind, ast::Program},
     ast_visit::{Visit, utf8_to_utf16::Utf8ToUtf16},
     codegen::{CodeGenerator, CodegenOptions},
     isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions},
     minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions},
     parser::{ParseOptions, Parser, ParserReturn},
     semantic::{
-        ReferenceId, ScopeFlags, ScopeId, Scoping, SemanticBuilder, SymbolFlags,
+        AstNodes, ReferenceId, ScopeFlags, ScopeId, Scoping, SemanticBuilder, SymbolFlags,
+        cfg::{ControlFlowGraph, EdgeType},
         dot::{DebugDot, DebugDotContext},
     },
-    span::{SourceType, Span},
+    span::{GetSpan, SourceType, Span},
     syntax::reference::Reference,
     transformer::{TransformOptions, Transformer},
 };
 use oxc_index::Idx;
-use oxc_linter::{ConfigStoreBuilder, LintOptions, Linter, ModuleRecord};
+use oxc_linter::{ConfigStoreBuilder, LintOptions, Linter, ModuleRecord, PossibleFixes};
 use oxc_napi::OxcError;
 use oxc_prettier::{Prettier, PrettierOptions};
+use petgraph::{Direction, graph::NodeIndex, visit::EdgeRef};
 use serde::Serialize;
 
-use crate::options::{OxcOptions, OxcRunOptions};
+use crate::{
+    coverage::CoverageInstrumenter,
+    options::{OxcOptions, OxcRunOptions},
+};
 
+mod coverage;
 mod options;
 
 #[derive(Default)]
@@ -38,15 +45,23 @@ pub struct Oxc {
     pub ast_json: String,
     pub ir: String,
     pub control_flow_graph: String,
+    pub dead_code_json: String,
     pub symbols_json: String,
     pub scope_text: String,
     pub codegen_text: String,
     pub codegen_sourcemap_text: Option<String>,
+    pub coverage_map_json: String,
     pub formatted_text: String,
     pub prettier_formatted_text: String,
     pub prettier_ir_text: String,
     comments: Vec<Comment>,
-    diagnostics: RefCell<Vec<oxc::diagnostics::OxcDiagnostic>>,
+    diagnostics: RefCell<Vec<DiagnosticEntry>>,
+}
+
+#[derive(Clone)]
+struct DiagnosticEntry {
+    error: oxc::diagnostics::OxcDiagnostic,
+    suggestions: Vec<DiagnosticSuggestion>,
 }
 
 #[derive(Clone)]
@@ -72,6 +87,27 @@ pub struct OxcDiagnostic {
     pub end: u32,
     pub severity: String,
     pub message: String,
+    pub code: Option<String>,
+    pub help: Option<String>,
+    pub labels: Vec<DiagnosticLabel>,
+    pub suggestions: Vec<DiagnosticSuggestion>,
+}
+
+#[derive(Default, Clone)]
+#[napi(object)]
+pub struct DiagnosticLabel {
+    pub start: u32,
+    pub end: u32,
+    pub message: Option<String>,
+}
+
+#[derive(Default, Clone)]
+#[napi(object)]
+pub struct DiagnosticSuggestion {
+    pub start: u32,
+    pub end: u32,
+    pub replacement: String,
+    pub applicability: String,
 }
 
 #[napi]
@@ -83,7 +119,7 @@ impl Oxc {
 
     #[napi]
     pub fn get_diagnostics2(&self) -> Vec<OxcError> {
-        self.diagnostics.borrow().clone().into_iter().map(OxcError::from).collect()
+        self.diagnostics.borrow().iter().map(|entry| OxcError::from(entry.error.clone())).collect()
     }
 
     #[napi]
@@ -91,24 +127,39 @@ impl Oxc {
         self.diagnostics
             .borrow()
             .iter()
-            .flat_map(|error| match &error.labels {
-                Some(labels) => labels
-                    .iter()
-                    .map(|label| OxcDiagnostic {
-                        #[expect(clippy::cast_possible_truncation)]
-                        start: label.offset() as u32,
-                        #[expect(clippy::cast_possible_truncation)]
-                        end: (label.offset() + label.len()) as u32,
-                        severity: format!("{:?}", error.severity),
-                        message: format!("{error}"),
-                    })
-                    .collect::<Vec<_>>(),
-                None => vec![OxcDiagnostic {
-                    start: 0,
-                    end: 0,
+            .map(|entry| {
+                let error = &entry.error;
+                let labels = error.labels.as_ref().map_or_else(Vec::new, |labels| {
+                    labels
+                        .iter()
+                        .map(|label| {
+                            #[expect(clippy::cast_possible_truncation)]
+                            let start = label.offset() as u32;
+                            #[expect(clippy::cast_possible_truncation)]
+                            let end = (label.offset() + label.len()) as u32;
+                            DiagnosticLabel {
+                                start,
+                                end,
+                                message: label.label().map(ToString::to_string),
+                            }
+                        })
+                        .collect()
+                });
+                // The primary span mirrors the first label; the spans of any further
+                // labels are only available through `labels`.
+                let (start, end) =
+                    labels.first().map_or((0, 0), |label| (label.start, label.end));
+                let code = error.code.to_string();
+                OxcDiagnostic {
+                    start,
+                    end,
                     severity: format!("{:?}", error.severity),
                     message: format!("{error}"),
-                }],
+                    code: (!code.is_empty()).then_some(code),
+                    help: error.help.as_ref().map(ToString::to_string),
+                    labels,
+                    suggestions: entry.suggestions.clone(),
+                }
             })
             .collect::<Vec<_>>()
     }
@@ -188,6 +239,11 @@ impl Oxc {
                 control_flow_options.verbose.unwrap_or_default(),
             ))
         });
+        if run_options.dead_code.unwrap_or_default() {
+            self.dead_code_json = semantic
+                .cfg()
+                .map_or_else(String::default, |cfg| Self::get_dead_code_json(cfg, semantic.nodes()));
+        }
         if run_options.syntax.unwrap_or_default() {
             self.save_diagnostics(
                 errors.into_iter().chain(semantic_ret.errors).collect::<Vec<_>>(),
@@ -256,6 +312,12 @@ impl Oxc {
             }
         }
 
+        if run_options.coverage.unwrap_or_default() {
+            let converter = Utf8ToUtf16::new(&source_text).converter();
+            self.coverage_map_json =
+                CoverageInstrumenter::new(&allocator, converter).build(&mut program);
+        }
+
         let symbol_table = if minifier_options.compress.unwrap_or_default()
             || minifier_options.mangle.unwrap_or_default()
         {
@@ -314,8 +376,32 @@ impl Oxc {
                 Rc::clone(&semantic),
                 Arc::clone(module_record),
             );
-            let diagnostics = linter_ret.into_iter().map(|e| e.error).collect();
-            self.save_diagnostics(diagnostics);
+            self.diagnostics.borrow_mut().extend(linter_ret.into_iter().map(|message| {
+                DiagnosticEntry {
+                    error: message.error,
+                    suggestions: Self::get_suggestions(&message.fix),
+                }
+            }));
+        }
+    }
+
+    fn get_suggestions(fix: &PossibleFixes) -> Vec<DiagnosticSuggestion> {
+        let to_suggestion = |fix: &oxc_linter::Fix, applicability: &str| DiagnosticSuggestion {
+            start: fix.span.start,
+            end: fix.span.end,
+            replacement: fix.content.to_string(),
+            applicability: applicability.to_string(),
+        };
+        match fix {
+            PossibleFixes::None => vec![],
+            // A single fix is unambiguous: it's safe to apply without review.
+            PossibleFixes::Single(fix) => vec![to_suggestion(fix, "MachineApplicable")],
+            // `Multiple` holds mutually-exclusive alternative fixes for the same diagnostic,
+            // so applying all of them at once would conflict; mark them as needing a choice
+            // rather than machine-applicable.
+            PossibleFixes::Multiple(fixes) => {
+                fixes.iter().map(|fix| to_suggestion(fix, "MaybeIncorrect")).collect()
+            }
         }
     }
 
@@ -443,8 +529,74 @@ impl Oxc {
         serde_json::to_string_pretty(&data).map_err(|e| napi::Error::from_reason(e.to_string()))
     }
 
+    fn get_dead_code_json(cfg: &ControlFlowGraph, nodes: &AstNodes) -> String {
+        #[derive(Serialize)]
+        struct DeadCodeSpan {
+            start: u32,
+            end: u32,
+            kind: &'static str,
+        }
+
+        let graph = cfg.graph();
+
+        // The program root is always reachable, and so is the entry block of every
+        // function body: a `NewFunction` edge links a function's declaration site to
+        // its own first basic block, which executes independently of whether the
+        // surrounding code that declares it is itself reachable.
+        let mut stack = vec![NodeIndex::new(0)];
+        stack.extend(
+            graph
+                .edge_references()
+                .filter(|edge| matches!(edge.weight(), EdgeType::NewFunction))
+                .map(|edge| edge.target()),
+        );
+
+        let mut reachable = HashSet::new();
+        while let Some(block) = stack.pop() {
+            if !reachable.insert(block) {
+                continue;
+            }
+            // Every outgoing edge is followed except `Unreachable` ones, which lead to
+            // the code after a `return`/`throw`; blocks only reached via `throw`/`return`
+            // edges are still marked reachable.
+            stack.extend(
+                graph
+                    .edges_directed(block, Direction::Outgoing)
+                    .filter(|edge| !matches!(edge.weight(), EdgeType::Unreachable))
+                    .map(|edge| edge.target()),
+            );
+        }
+
+        let dead_code = graph
+            .node_indices()
+            .filter(|block| !reachable.contains(block))
+            .flat_map(|block| {
+                let basic_block = cfg.basic_block(graph[block]);
+                basic_block
+                    .instructions()
+                    .iter()
+                    .filter_map(|instr| instr.node_id)
+                    .map(|node_id| {
+                        let span = nodes.kind(node_id).span();
+                        let kind = match nodes.kind(node_id) {
+                            AstKind::Function(_) => "unreachable-function",
+                            _ => "unreachable-statement",
+                        };
+                        DeadCodeSpan { start: span.start, end: span.end, kind }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_string(&dead_code).unwrap_or_default()
+    }
+
     fn save_diagnostics(&self, diagnostics: Vec<oxc::diagnostics::OxcDiagnostic>) {
-        self.diagnostics.borrow_mut().extend(diagnostics);
+        self.diagnostics.borrow_mut().extend(
+            diagnostics
+                .into_iter()
+                .map(|error| DiagnosticEntry { error, suggestions: vec![] }),
+        );
     }
 
     fn convert_ast(&mut self, program: &mut Program) {